@@ -0,0 +1,73 @@
+use std::fmt::{self, Display};
+use std::str;
+
+use header::{Header, HeaderFormat};
+
+/// The `Access-Control-Allow-Credentials` response header,
+/// part of [CORS](http://www.w3.org/TR/cors/#access-control-allow-credentials-response-header)
+///
+/// > The Access-Control-Allow-Credentials header indicates whether the
+/// > response to request can be exposed when the omit credentials flag is
+/// > unset. When part of the response to a preflight request it indicates
+/// > that the actual request can include user credentials.
+///
+/// Since there is only one acceptable field value, the header struct does not
+/// accept any values at all. Setting an empty `AccessControlAllowCredentials`
+/// header is sufficient. See the examples below.
+///
+/// # ABNF
+/// ```plain
+/// Access-Control-Allow-Credentials: "Access-Control-Allow-Credentials" ":" "true"
+/// ```
+///
+/// # Example values
+/// * "true"
+///
+/// # Examples
+/// ```
+/// use hyper::header::{Headers, AccessControlAllowCredentials};
+///
+/// let mut headers = Headers::new();
+/// headers.set(AccessControlAllowCredentials);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct AccessControlAllowCredentials;
+
+const ACCESS_CONTROL_ALLOW_CREDENTIALS_TRUE: &'static str = "true";
+
+impl Header for AccessControlAllowCredentials {
+    fn header_name() -> &'static str {
+        "Access-Control-Allow-Credentials"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> ::Result<AccessControlAllowCredentials> {
+        if raw.len() == 1 {
+            let text = unsafe { &raw.get_unchecked(0)[..] };
+            if let Ok(text) = str::from_utf8(text) {
+                if text == ACCESS_CONTROL_ALLOW_CREDENTIALS_TRUE {
+                    return Ok(AccessControlAllowCredentials);
+                }
+            }
+        }
+        Err(::Error::Header)
+    }
+}
+
+impl HeaderFormat for AccessControlAllowCredentials {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(ACCESS_CONTROL_ALLOW_CREDENTIALS_TRUE)
+    }
+}
+
+impl Display for AccessControlAllowCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)
+    }
+}
+
+#[cfg(test)]
+mod test_access_control_allow_credentials {
+    use header::*;
+    use super::AccessControlAllowCredentials as HeaderField;
+    test_header!(test1, vec![b"true"]);
+}
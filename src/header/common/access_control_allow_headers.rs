@@ -0,0 +1,76 @@
+use std::fmt;
+
+use unicase::UniCase;
+use header::{Header, HeaderFormat};
+use header::parsing::{from_comma_delimited, fmt_comma_delimited};
+
+/// The `Access-Control-Allow-Headers` response header,
+/// part of [CORS](http://www.w3.org/TR/cors/#access-control-allow-headers-response-header)
+///
+/// The `Access-Control-Allow-Headers` header indicates, as part of the
+/// response to a preflight request, which header field names can be used
+/// during the actual request.
+///
+/// # ABNF
+/// ```plain
+/// Access-Control-Allow-Headers: "Access-Control-Allow-Headers" ":" #field-name
+/// ```
+///
+/// # Example values
+/// * `accept-language, date`
+///
+/// # Examples
+/// ```
+/// use hyper::header::{Headers, AccessControlAllowHeaders};
+/// use unicase::UniCase;
+///
+/// let mut headers = Headers::new();
+/// headers.set(
+///     AccessControlAllowHeaders(vec![UniCase("date".to_owned())])
+/// );
+/// ```
+/// ```
+/// use hyper::header::{Headers, AccessControlAllowHeaders};
+/// use unicase::UniCase;
+///
+/// let mut headers = Headers::new();
+/// headers.set(
+///     AccessControlAllowHeaders(vec![
+///         UniCase("accept-language".to_owned()),
+///         UniCase("date".to_owned()),
+///     ])
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct AccessControlAllowHeaders(pub Vec<UniCase<String>>);
+
+impl Header for AccessControlAllowHeaders {
+    fn header_name() -> &'static str {
+        "Access-Control-Allow-Headers"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> ::Result<AccessControlAllowHeaders> {
+        from_comma_delimited(raw).map(AccessControlAllowHeaders)
+    }
+}
+
+impl HeaderFormat for AccessControlAllowHeaders {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let AccessControlAllowHeaders(ref parts) = *self;
+        fmt_comma_delimited(f, parts)
+    }
+}
+
+impl fmt::Display for AccessControlAllowHeaders {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)
+    }
+}
+
+#[cfg(test)]
+mod test_access_control_allow_headers {
+    use header::*;
+    use unicase::UniCase;
+    use super::AccessControlAllowHeaders as HeaderField;
+    test_header!(test1, vec![b"accept-language, date"], Some(HeaderField(vec![UniCase("accept-language".to_owned()), UniCase("date".to_owned())])));
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+
+use method::Method;
+use header::{Header, HeaderFormat};
+use header::parsing::{from_comma_delimited, fmt_comma_delimited};
+
+/// The `Access-Control-Allow-Methods` response header,
+/// part of [CORS](http://www.w3.org/TR/cors/#access-control-allow-methods-response-header)
+///
+/// The `Access-Control-Allow-Methods` header indicates, as part of the
+/// response to a preflight request, which methods can be used during the
+/// actual request.
+///
+/// # ABNF
+/// ```plain
+/// Access-Control-Allow-Methods: "Access-Control-Allow-Methods" ":" #Method
+/// ```
+///
+/// # Example values
+/// * `PUT, DELETE, XMODIFY`
+///
+/// # Examples
+/// ```
+/// use hyper::header::{Headers, AccessControlAllowMethods};
+/// use hyper::method::Method;
+///
+/// let mut headers = Headers::new();
+/// headers.set(
+///     AccessControlAllowMethods(vec![Method::Get])
+/// );
+/// ```
+/// ```
+/// use hyper::header::{Headers, AccessControlAllowMethods};
+/// use hyper::method::Method;
+///
+/// let mut headers = Headers::new();
+/// headers.set(
+///     AccessControlAllowMethods(vec![
+///         Method::Get,
+///         Method::Post,
+///         Method::Patch,
+///         Method::Extension("COPY".to_owned()),
+///     ])
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct AccessControlAllowMethods(pub Vec<Method>);
+
+impl Header for AccessControlAllowMethods {
+    fn header_name() -> &'static str {
+        "Access-Control-Allow-Methods"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> ::Result<AccessControlAllowMethods> {
+        from_comma_delimited(raw).map(AccessControlAllowMethods)
+    }
+}
+
+impl HeaderFormat for AccessControlAllowMethods {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let AccessControlAllowMethods(ref parts) = *self;
+        fmt_comma_delimited(f, parts)
+    }
+}
+
+impl fmt::Display for AccessControlAllowMethods {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)
+    }
+}
+
+#[cfg(test)]
+mod test_access_control_allow_methods {
+    use header::*;
+    use method::Method;
+    use super::AccessControlAllowMethods as HeaderField;
+    test_header!(test1, vec![b"GET, HEAD, POST"], Some(HeaderField(vec![Method::Get, Method::Head, Method::Post])));
+}
@@ -1,7 +1,6 @@
 use std::fmt::{self, Display};
 use std::str;
 
-use url::Url;
 use header::{Header, HeaderFormat};
 
 /// The `Access-Control-Allow-Origin` response header,
@@ -20,7 +19,7 @@ use header::{Header, HeaderFormat};
 /// * `null`
 /// * `*`
 /// * `http://google.com/`
-/// 
+///
 /// # Examples
 /// ```
 /// use hyper::header::{Headers, AccessControlAllowOrigin};
@@ -40,11 +39,10 @@ use header::{Header, HeaderFormat};
 /// ```
 /// ```
 /// use hyper::header::{Headers, AccessControlAllowOrigin};
-/// use hyper::Url;
 ///
 /// let mut headers = Headers::new();
 /// headers.set(
-///     AccessControlAllowOrigin::Value(Url::parse("http://hyper.rs").unwrap())
+///     AccessControlAllowOrigin::Value("http://hyper.rs".to_owned())
 /// );
 /// ```
 #[derive(Clone, PartialEq, Debug)]
@@ -54,7 +52,7 @@ pub enum AccessControlAllowOrigin {
     /// A hidden origin
     Null,
     /// Allow one particular origin
-    Value(Url),
+    Value(String),
 }
 
 impl Header for AccessControlAllowOrigin {
@@ -67,7 +65,7 @@ impl Header for AccessControlAllowOrigin {
             match unsafe { &raw.get_unchecked(0)[..] } {
                 b"*" => Ok(AccessControlAllowOrigin::Any),
                 b"null" => Ok(AccessControlAllowOrigin::Null),
-                r => Ok(AccessControlAllowOrigin::Value(try!(Url::parse(try!(str::from_utf8(r))))))
+                r => Ok(AccessControlAllowOrigin::Value(try!(str::from_utf8(r)).to_owned()))
             }
         } else { Err(::Error::Header) }
     }
@@ -78,7 +76,7 @@ impl HeaderFormat for AccessControlAllowOrigin {
         match *self {
             AccessControlAllowOrigin::Any => f.write_str("*"),
             AccessControlAllowOrigin::Null => f.write_str("null"),
-            AccessControlAllowOrigin::Value(ref url) => Display::fmt(url, f),
+            AccessControlAllowOrigin::Value(ref origin) => f.write_str(origin),
         }
     }
 }
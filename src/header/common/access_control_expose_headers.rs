@@ -0,0 +1,66 @@
+use std::fmt;
+
+use unicase::UniCase;
+use header::{Header, HeaderFormat};
+use header::parsing::{from_comma_delimited, fmt_comma_delimited};
+
+/// The `Access-Control-Expose-Headers` response header,
+/// part of [CORS](http://www.w3.org/TR/cors/#access-control-expose-headers-response-header)
+///
+/// The `Access-Control-Expose-Headers` header indicates which headers are
+/// safe to expose to the API of a CORS API specification.
+///
+/// # ABNF
+/// ```plain
+/// Access-Control-Expose-Headers: "Access-Control-Expose-Headers" ":" #field-name
+/// ```
+///
+/// # Example values
+/// * `content-encoding, x-custom`
+///
+/// # Examples
+/// ```
+/// use hyper::header::{Headers, AccessControlExposeHeaders};
+/// use unicase::UniCase;
+///
+/// let mut headers = Headers::new();
+/// headers.set(
+///     AccessControlExposeHeaders(vec![
+///         UniCase("content-encoding".to_owned()),
+///         UniCase("x-custom".to_owned()),
+///     ])
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct AccessControlExposeHeaders(pub Vec<UniCase<String>>);
+
+impl Header for AccessControlExposeHeaders {
+    fn header_name() -> &'static str {
+        "Access-Control-Expose-Headers"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> ::Result<AccessControlExposeHeaders> {
+        from_comma_delimited(raw).map(AccessControlExposeHeaders)
+    }
+}
+
+impl HeaderFormat for AccessControlExposeHeaders {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let AccessControlExposeHeaders(ref parts) = *self;
+        fmt_comma_delimited(f, parts)
+    }
+}
+
+impl fmt::Display for AccessControlExposeHeaders {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)
+    }
+}
+
+#[cfg(test)]
+mod test_access_control_expose_headers {
+    use header::*;
+    use unicase::UniCase;
+    use super::AccessControlExposeHeaders as HeaderField;
+    test_header!(test1, vec![b"content-encoding, x-custom"], Some(HeaderField(vec![UniCase("content-encoding".to_owned()), UniCase("x-custom".to_owned())])));
+}
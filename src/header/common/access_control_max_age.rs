@@ -0,0 +1,58 @@
+use std::fmt;
+
+use header::{Header, HeaderFormat};
+use header::parsing::from_one_raw_str;
+
+/// The `Access-Control-Max-Age` response header,
+/// part of [CORS](http://www.w3.org/TR/cors/#access-control-max-age-response-header)
+///
+/// The `Access-Control-Max-Age` header indicates how long the results of a
+/// preflight request can be cached in a preflight result cache.
+///
+/// # ABNF
+/// ```plain
+/// Access-Control-Max-Age: "Access-Control-Max-Age" ":" delta-seconds
+/// ```
+///
+/// # Example values
+/// * `531`
+///
+/// # Examples
+/// ```
+/// use hyper::header::{Headers, AccessControlMaxAge};
+///
+/// let mut headers = Headers::new();
+/// headers.set(AccessControlMaxAge(1728000u32));
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct AccessControlMaxAge(pub u32);
+
+impl Header for AccessControlMaxAge {
+    fn header_name() -> &'static str {
+        "Access-Control-Max-Age"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> ::Result<AccessControlMaxAge> {
+        from_one_raw_str(raw).map(AccessControlMaxAge)
+    }
+}
+
+impl HeaderFormat for AccessControlMaxAge {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let AccessControlMaxAge(ref num) = *self;
+        write!(f, "{}", num)
+    }
+}
+
+impl fmt::Display for AccessControlMaxAge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)
+    }
+}
+
+#[cfg(test)]
+mod test_access_control_max_age {
+    use header::*;
+    use super::AccessControlMaxAge as HeaderField;
+    test_header!(test1, vec![b"531"], Some(HeaderField(531u32)));
+}
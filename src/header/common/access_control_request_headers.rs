@@ -0,0 +1,75 @@
+use std::fmt;
+
+use unicase::UniCase;
+use header::{Header, HeaderFormat};
+use header::parsing::{from_comma_delimited, fmt_comma_delimited};
+
+/// The `Access-Control-Request-Headers` request header,
+/// part of [CORS](http://www.w3.org/TR/cors/#access-control-request-headers-request-header)
+///
+/// The `Access-Control-Request-Headers` header indicates which headers will
+/// be used in the actual request as part of the preflight request.
+///
+/// # ABNF
+/// ```plain
+/// Access-Control-Request-Headers: "Access-Control-Request-Headers" ":" #field-name
+/// ```
+///
+/// # Example values
+/// * `accept-language, date`
+///
+/// # Examples
+/// ```
+/// use hyper::header::{Headers, AccessControlRequestHeaders};
+/// use unicase::UniCase;
+///
+/// let mut headers = Headers::new();
+/// headers.set(
+///     AccessControlRequestHeaders(vec![UniCase("date".to_owned())])
+/// );
+/// ```
+/// ```
+/// use hyper::header::{Headers, AccessControlRequestHeaders};
+/// use unicase::UniCase;
+///
+/// let mut headers = Headers::new();
+/// headers.set(
+///     AccessControlRequestHeaders(vec![
+///         UniCase("accept-language".to_owned()),
+///         UniCase("date".to_owned()),
+///     ])
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct AccessControlRequestHeaders(pub Vec<UniCase<String>>);
+
+impl Header for AccessControlRequestHeaders {
+    fn header_name() -> &'static str {
+        "Access-Control-Request-Headers"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> ::Result<AccessControlRequestHeaders> {
+        from_comma_delimited(raw).map(AccessControlRequestHeaders)
+    }
+}
+
+impl HeaderFormat for AccessControlRequestHeaders {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let AccessControlRequestHeaders(ref parts) = *self;
+        fmt_comma_delimited(f, parts)
+    }
+}
+
+impl fmt::Display for AccessControlRequestHeaders {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)
+    }
+}
+
+#[cfg(test)]
+mod test_access_control_request_headers {
+    use header::*;
+    use unicase::UniCase;
+    use super::AccessControlRequestHeaders as HeaderField;
+    test_header!(test1, vec![b"accept-language, date"], Some(HeaderField(vec![UniCase("accept-language".to_owned()), UniCase("date".to_owned())])));
+}
@@ -0,0 +1,61 @@
+use std::fmt;
+
+use method::Method;
+use header::{Header, HeaderFormat};
+use header::parsing::from_one_raw_str;
+
+/// The `Access-Control-Request-Method` request header,
+/// part of [CORS](http://www.w3.org/TR/cors/#access-control-request-method-request-header)
+///
+/// The `Access-Control-Request-Method` header indicates which method will be
+/// used in the actual request as part of the preflight request.
+///
+/// # ABNF
+/// ```plain
+/// Access-Control-Request-Method: "Access-Control-Request-Method" ":" Method
+/// ```
+///
+/// # Example values
+/// * `GET`
+///
+/// # Examples
+/// ```
+/// use hyper::header::{Headers, AccessControlRequestMethod};
+/// use hyper::method::Method;
+///
+/// let mut headers = Headers::new();
+/// headers.set(AccessControlRequestMethod(Method::Get));
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct AccessControlRequestMethod(pub Method);
+
+impl Header for AccessControlRequestMethod {
+    fn header_name() -> &'static str {
+        "Access-Control-Request-Method"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> ::Result<AccessControlRequestMethod> {
+        from_one_raw_str(raw).map(AccessControlRequestMethod)
+    }
+}
+
+impl HeaderFormat for AccessControlRequestMethod {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let AccessControlRequestMethod(ref method) = *self;
+        write!(f, "{}", method)
+    }
+}
+
+impl fmt::Display for AccessControlRequestMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)
+    }
+}
+
+#[cfg(test)]
+mod test_access_control_request_method {
+    use header::*;
+    use method::Method;
+    use super::AccessControlRequestMethod as HeaderField;
+    test_header!(test1, vec![b"GET"], Some(HeaderField(Method::Get)));
+}
@@ -0,0 +1,358 @@
+//! A small CORS subsystem that turns a request's headers into the correct
+//! set of response headers.
+//!
+//! A [`Cors`](struct.Cors.html) value captures a server's CORS policy — the
+//! allowed origins, methods, headers, exposed headers, `max_age` and whether
+//! credentials are permitted — and knows how to answer both simple (actual)
+//! requests and `OPTIONS` preflight requests with the appropriate
+//! `Access-Control-*` headers.
+//!
+//! # Examples
+//! ```
+//! use hyper::header::{Cors, OriginPattern};
+//! use hyper::method::Method;
+//!
+//! let cors = Cors::builder()
+//!     .allow_origin(OriginPattern::new("https://*.example.com"))
+//!     .allow_methods(vec![Method::Get, Method::Post])
+//!     .max_age(3600)
+//!     .build();
+//! # let _ = cors;
+//! ```
+
+use std::str;
+
+use method::Method;
+use unicase::UniCase;
+
+use header::{
+    Headers,
+    AccessControlAllowOrigin,
+    AccessControlAllowMethods,
+    AccessControlAllowHeaders,
+    AccessControlExposeHeaders,
+    AccessControlMaxAge,
+    AccessControlAllowCredentials,
+    AccessControlRequestMethod,
+    AccessControlRequestHeaders,
+    OriginPattern,
+};
+
+/// The set of origins a [`Cors`](struct.Cors.html) policy will accept.
+#[derive(Clone, Debug)]
+pub enum AllowedOrigins {
+    /// Accept any origin.
+    Any,
+    /// Accept an origin matching one of the configured patterns.
+    Patterns(Vec<OriginPattern>),
+}
+
+/// A CORS policy describing which cross-origin requests are permitted and
+/// what the corresponding responses should advertise.
+///
+/// Construct one with [`Cors::builder`](struct.Cors.html#method.builder).
+#[derive(Clone, Debug)]
+pub struct Cors {
+    allowed_origins: AllowedOrigins,
+    allowed_methods: Vec<Method>,
+    allowed_headers: Vec<UniCase<String>>,
+    exposed_headers: Vec<UniCase<String>>,
+    max_age: Option<u32>,
+    allow_credentials: bool,
+}
+
+impl Cors {
+    /// Start building a policy. The returned builder accepts any origin and
+    /// no methods or headers until configured.
+    pub fn builder() -> CorsBuilder {
+        CorsBuilder {
+            cors: Cors {
+                allowed_origins: AllowedOrigins::Any,
+                allowed_methods: Vec::new(),
+                allowed_headers: Vec::new(),
+                exposed_headers: Vec::new(),
+                max_age: None,
+                allow_credentials: false,
+            },
+        }
+    }
+
+    /// Produce the CORS response headers for a simple (actual) request.
+    ///
+    /// The request's `Origin` is validated against the policy; on a match the
+    /// `Access-Control-Allow-Origin` value is selected (echoed explicitly when
+    /// credentials are allowed) and `Access-Control-Allow-Credentials` /
+    /// `Access-Control-Expose-Headers` are added as configured. A request with
+    /// no `Origin` or a non-matching one yields an empty set of headers rather
+    /// than an error.
+    pub fn simple_response(&self, request: &Headers) -> Headers {
+        let mut headers = Headers::new();
+        let origin = match request_origin(request) {
+            Some(origin) => origin,
+            None => return headers,
+        };
+        let allow_origin = match self.allow_origin_for(&origin) {
+            Some(value) => value,
+            None => return headers,
+        };
+
+        headers.set(allow_origin);
+        if self.allow_credentials {
+            headers.set(AccessControlAllowCredentials);
+        }
+        if !self.exposed_headers.is_empty() {
+            headers.set(AccessControlExposeHeaders(self.exposed_headers.clone()));
+        }
+        headers
+    }
+
+    /// Produce the CORS response headers for an `OPTIONS` preflight request.
+    ///
+    /// The `Access-Control-Request-Method` and `-Request-Headers` are checked
+    /// against the policy; if either names something not allowed, or the
+    /// `Origin` does not match, an empty set of headers is returned. Otherwise
+    /// the allowed methods, headers and `max_age` are emitted alongside the
+    /// origin and, when configured, `Access-Control-Allow-Credentials`.
+    pub fn preflight_response(&self, request: &Headers) -> Headers {
+        let mut headers = Headers::new();
+        let origin = match request_origin(request) {
+            Some(origin) => origin,
+            None => return headers,
+        };
+        let allow_origin = match self.allow_origin_for(&origin) {
+            Some(value) => value,
+            None => return headers,
+        };
+
+        if let Some(&AccessControlRequestMethod(ref method)) = request.get() {
+            if !self.method_allowed(method) {
+                return headers;
+            }
+        }
+        if let Some(&AccessControlRequestHeaders(ref requested)) = request.get() {
+            if !requested.iter().all(|header| self.header_allowed(header)) {
+                return headers;
+            }
+        }
+
+        headers.set(allow_origin);
+        if self.allow_credentials {
+            headers.set(AccessControlAllowCredentials);
+        }
+        if !self.allowed_methods.is_empty() {
+            headers.set(AccessControlAllowMethods(self.allowed_methods.clone()));
+        }
+        if !self.allowed_headers.is_empty() {
+            headers.set(AccessControlAllowHeaders(self.allowed_headers.clone()));
+        }
+        if let Some(max_age) = self.max_age {
+            headers.set(AccessControlMaxAge(max_age));
+        }
+        headers
+    }
+
+    /// Select the `Access-Control-Allow-Origin` value for `origin`, or `None`
+    /// if the origin is not allowed. When credentials are permitted the origin
+    /// is always echoed explicitly — `*` is never used.
+    fn allow_origin_for(&self, origin: &str) -> Option<AccessControlAllowOrigin> {
+        match self.allowed_origins {
+            AllowedOrigins::Any => {
+                if self.allow_credentials {
+                    Some(AccessControlAllowOrigin::Value(origin.to_owned()))
+                } else {
+                    Some(AccessControlAllowOrigin::Any)
+                }
+            }
+            AllowedOrigins::Patterns(ref patterns) => {
+                if patterns.iter().any(|pattern| pattern.matches(origin)) {
+                    Some(AccessControlAllowOrigin::Value(origin.to_owned()))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn method_allowed(&self, method: &Method) -> bool {
+        self.allowed_methods.iter().any(|allowed| allowed == method)
+    }
+
+    fn header_allowed(&self, header: &UniCase<String>) -> bool {
+        self.allowed_headers.iter().any(|allowed| allowed == header)
+    }
+}
+
+/// Read the request `Origin` header as an opaque, byte-exact string.
+fn request_origin(request: &Headers) -> Option<String> {
+    request.get_raw("Origin").and_then(|raw| {
+        if raw.len() == 1 {
+            str::from_utf8(&raw[0]).ok().map(|origin| origin.to_owned())
+        } else {
+            None
+        }
+    })
+}
+
+/// Builder for [`Cors`](struct.Cors.html).
+#[derive(Clone, Debug)]
+pub struct CorsBuilder {
+    cors: Cors,
+}
+
+impl CorsBuilder {
+    /// Add an allowed origin pattern. The first call switches the policy away
+    /// from "any origin" to the configured allow-list.
+    pub fn allow_origin(mut self, pattern: OriginPattern) -> CorsBuilder {
+        match self.cors.allowed_origins {
+            AllowedOrigins::Patterns(ref mut patterns) => patterns.push(pattern),
+            AllowedOrigins::Any => {
+                self.cors.allowed_origins = AllowedOrigins::Patterns(vec![pattern]);
+            }
+        }
+        self
+    }
+
+    /// Accept any origin (the default).
+    pub fn allow_any_origin(mut self) -> CorsBuilder {
+        self.cors.allowed_origins = AllowedOrigins::Any;
+        self
+    }
+
+    /// Set the methods advertised in preflight responses.
+    pub fn allow_methods(mut self, methods: Vec<Method>) -> CorsBuilder {
+        self.cors.allowed_methods = methods;
+        self
+    }
+
+    /// Set the request headers advertised in preflight responses.
+    pub fn allow_headers(mut self, headers: Vec<UniCase<String>>) -> CorsBuilder {
+        self.cors.allowed_headers = headers;
+        self
+    }
+
+    /// Set the response headers exposed to the client.
+    pub fn expose_headers(mut self, headers: Vec<UniCase<String>>) -> CorsBuilder {
+        self.cors.exposed_headers = headers;
+        self
+    }
+
+    /// Set how long, in seconds, a preflight response may be cached.
+    pub fn max_age(mut self, max_age: u32) -> CorsBuilder {
+        self.cors.max_age = Some(max_age);
+        self
+    }
+
+    /// Allow credentialed requests. The matching origin is then always echoed
+    /// explicitly in `Access-Control-Allow-Origin`.
+    pub fn allow_credentials(mut self, allow: bool) -> CorsBuilder {
+        self.cors.allow_credentials = allow;
+        self
+    }
+
+    /// Finish building the policy.
+    pub fn build(self) -> Cors {
+        self.cors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use method::Method;
+    use unicase::UniCase;
+    use header::{
+        Headers,
+        AccessControlAllowOrigin,
+        AccessControlAllowMethods,
+        AccessControlAllowCredentials,
+        AccessControlRequestMethod,
+        OriginPattern,
+    };
+    use super::Cors;
+
+    fn request_with_origin(origin: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.set_raw("Origin", vec![origin.as_bytes().to_vec()]);
+        headers
+    }
+
+    #[test]
+    fn simple_echoes_matching_origin() {
+        let cors = Cors::builder()
+            .allow_origin(OriginPattern::new("http://*.example.com"))
+            .build();
+        let response = cors.simple_response(&request_with_origin("http://api.example.com"));
+        assert_eq!(
+            response.get::<AccessControlAllowOrigin>(),
+            Some(&AccessControlAllowOrigin::Value("http://api.example.com".to_owned()))
+        );
+    }
+
+    #[test]
+    fn simple_rejects_unknown_origin() {
+        let cors = Cors::builder()
+            .allow_origin(OriginPattern::new("http://*.example.com"))
+            .build();
+        let response = cors.simple_response(&request_with_origin("http://evil.test"));
+        assert!(response.get::<AccessControlAllowOrigin>().is_none());
+    }
+
+    #[test]
+    fn any_origin_uses_star_without_credentials() {
+        let cors = Cors::builder().build();
+        let response = cors.simple_response(&request_with_origin("http://example.com"));
+        assert_eq!(
+            response.get::<AccessControlAllowOrigin>(),
+            Some(&AccessControlAllowOrigin::Any)
+        );
+    }
+
+    #[test]
+    fn credentials_force_explicit_origin() {
+        let cors = Cors::builder().allow_credentials(true).build();
+        let response = cors.simple_response(&request_with_origin("http://example.com"));
+        assert_eq!(
+            response.get::<AccessControlAllowOrigin>(),
+            Some(&AccessControlAllowOrigin::Value("http://example.com".to_owned()))
+        );
+        assert!(response.get::<AccessControlAllowCredentials>().is_some());
+    }
+
+    #[test]
+    fn preflight_advertises_allowed_methods() {
+        let cors = Cors::builder()
+            .allow_methods(vec![Method::Get, Method::Post])
+            .max_age(3600)
+            .build();
+        let mut request = request_with_origin("http://example.com");
+        request.set(AccessControlRequestMethod(Method::Post));
+        let response = cors.preflight_response(&request);
+        assert_eq!(
+            response.get::<AccessControlAllowMethods>(),
+            Some(&AccessControlAllowMethods(vec![Method::Get, Method::Post]))
+        );
+    }
+
+    #[test]
+    fn preflight_rejects_disallowed_method() {
+        let cors = Cors::builder()
+            .allow_methods(vec![Method::Get])
+            .build();
+        let mut request = request_with_origin("http://example.com");
+        request.set(AccessControlRequestMethod(Method::Delete));
+        let response = cors.preflight_response(&request);
+        assert!(response.get::<AccessControlAllowOrigin>().is_none());
+    }
+
+    #[test]
+    fn preflight_rejects_disallowed_headers() {
+        let cors = Cors::builder()
+            .allow_methods(vec![Method::Get])
+            .allow_headers(vec![UniCase("x-allowed".to_owned())])
+            .build();
+        let mut request = request_with_origin("http://example.com");
+        request.set(AccessControlRequestMethod(Method::Get));
+        request.set(::header::AccessControlRequestHeaders(vec![UniCase("x-forbidden".to_owned())]));
+        let response = cors.preflight_response(&request);
+        assert!(response.get::<AccessControlAllowOrigin>().is_none());
+    }
+}
@@ -0,0 +1,192 @@
+//! Glob-style matching of request origins against configured allow-lists.
+
+/// A single component of an origin that may be a literal or a wildcard.
+#[derive(Clone, PartialEq, Debug)]
+enum Part {
+    /// Matches any value.
+    Any,
+    /// Matches a literal value (compared case-insensitively).
+    Exact(String),
+    /// Matches a glob of the form produced by a `*` inside a host, e.g.
+    /// `*.example.com`. The stored string is the already lower-cased pattern.
+    Glob(String),
+}
+
+impl Part {
+    fn matches(&self, value: &str) -> bool {
+        match *self {
+            Part::Any => true,
+            Part::Exact(ref s) => s.to_lowercase() == value.to_lowercase(),
+            Part::Glob(ref pat) => glob_match(pat, &value.to_lowercase()),
+        }
+    }
+}
+
+/// A pattern used to decide whether a request `Origin` is allowed.
+///
+/// A pattern is either a bare `*`, which matches every origin, or a
+/// `scheme://host[:port]` triple in which the host may use `*` as a
+/// glob spanning subdomain labels (`http://*.example.com`) and the port
+/// may be `*` to match any port (`https://app.example.com:*`). Schemes
+/// and hosts are compared case-insensitively; ports are compared exactly
+/// unless wildcarded.
+///
+/// # Examples
+/// ```
+/// use hyper::header::OriginPattern;
+///
+/// let pattern = OriginPattern::new("http://*.example.com");
+/// assert!(pattern.matches("http://api.example.com"));
+/// assert!(!pattern.matches("https://api.example.com"));
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct OriginPattern {
+    inner: Inner,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Inner {
+    /// A bare `*` — matches everything.
+    Any,
+    /// A structured `scheme://host[:port]` pattern.
+    Triple {
+        scheme: Part,
+        host: Part,
+        port: Part,
+    },
+}
+
+impl OriginPattern {
+    /// Build a pattern from its textual form, e.g. `*`,
+    /// `http://*.example.com`, or `https://app.example.com:*`.
+    pub fn new(pattern: &str) -> OriginPattern {
+        if pattern == "*" {
+            return OriginPattern { inner: Inner::Any };
+        }
+        let (scheme, rest) = split_scheme(pattern);
+        let (host, port) = split_port(rest);
+        OriginPattern {
+            inner: Inner::Triple {
+                scheme: Part::Exact(scheme.to_owned()),
+                host: host_part(host),
+                port: port_part(port),
+            },
+        }
+    }
+
+    /// Test whether `origin` matches this pattern.
+    pub fn matches(&self, origin: &str) -> bool {
+        match self.inner {
+            Inner::Any => true,
+            Inner::Triple { ref scheme, ref host, ref port } => {
+                let (o_scheme, rest) = split_scheme(origin);
+                let (o_host, o_port) = split_port(rest);
+                scheme.matches(o_scheme) && host.matches(o_host) && port.matches(o_port)
+            }
+        }
+    }
+}
+
+/// Split `scheme://rest` into `(scheme, rest)`; if there is no `://` the
+/// whole string is treated as the remainder with an empty scheme.
+fn split_scheme(value: &str) -> (&str, &str) {
+    match value.find("://") {
+        Some(idx) => (&value[..idx], &value[idx + 3..]),
+        None => ("", value),
+    }
+}
+
+/// Split `host:port` into `(host, port)`; the port is empty when absent.
+fn split_port(value: &str) -> (&str, &str) {
+    match value.rfind(':') {
+        Some(idx) => (&value[..idx], &value[idx + 1..]),
+        None => (value, ""),
+    }
+}
+
+fn host_part(host: &str) -> Part {
+    if host == "*" {
+        Part::Any
+    } else if host.contains('*') {
+        Part::Glob(host.to_lowercase())
+    } else {
+        Part::Exact(host.to_owned())
+    }
+}
+
+fn port_part(port: &str) -> Part {
+    if port == "*" {
+        Part::Any
+    } else {
+        Part::Exact(port.to_owned())
+    }
+}
+
+/// Match `value` against a glob `pattern` where `*` matches any run of
+/// characters. Both are expected to be lower-cased by the caller.
+fn glob_match(pattern: &str, value: &str) -> bool {
+    let segments: Vec<&str> = pattern.split('*').collect();
+    if segments.len() == 1 {
+        return pattern == value;
+    }
+    let mut pos = 0;
+    for (i, segment) in segments.iter().enumerate() {
+        if segment.is_empty() {
+            continue;
+        }
+        if i == 0 {
+            if !value[pos..].starts_with(segment) {
+                return false;
+            }
+            pos += segment.len();
+        } else if i == segments.len() - 1 {
+            if !value[pos..].ends_with(segment) {
+                return false;
+            }
+        } else {
+            match value[pos..].find(segment) {
+                Some(idx) => pos += idx + segment.len(),
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OriginPattern;
+
+    #[test]
+    fn bare_star_matches_everything() {
+        let pattern = OriginPattern::new("*");
+        assert!(pattern.matches("http://example.com"));
+        assert!(pattern.matches("https://app.example.com:8080"));
+    }
+
+    #[test]
+    fn exact_origin() {
+        let pattern = OriginPattern::new("https://app.example.com");
+        assert!(pattern.matches("https://app.example.com"));
+        assert!(pattern.matches("https://APP.EXAMPLE.COM"));
+        assert!(!pattern.matches("http://app.example.com"));
+        assert!(!pattern.matches("https://app.example.com:8080"));
+    }
+
+    #[test]
+    fn host_wildcard_spans_labels() {
+        let pattern = OriginPattern::new("http://*.example.com");
+        assert!(pattern.matches("http://api.example.com"));
+        assert!(pattern.matches("http://a.b.example.com"));
+        assert!(!pattern.matches("http://example.org"));
+        assert!(!pattern.matches("https://api.example.com"));
+    }
+
+    #[test]
+    fn port_wildcard() {
+        let pattern = OriginPattern::new("https://app.example.com:*");
+        assert!(pattern.matches("https://app.example.com:8080"));
+        assert!(pattern.matches("https://app.example.com:443"));
+        assert!(!pattern.matches("http://app.example.com:8080"));
+    }
+}